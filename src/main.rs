@@ -1,27 +1,392 @@
 use chrono::{Datelike, Duration, Local, NaiveDate};
+use clap::Parser;
 use colored::*;
-use git2::{Repository, Sort};
+use git2::{Commit, Repository, Sort};
 use std::collections::HashMap;
 use std::env;
+use std::io::IsTerminal;
 use std::path::Path;
 
-fn main() {
-    let path = env::args().nth(1).unwrap_or_else(|| ".".to_string());
+/// Render a GitHub-style contribution calendar for a git repo
+#[derive(Parser)]
+#[command(name = "git-cal", about = "Render a GitHub-style contribution calendar for a git repo")]
+struct Args {
+    /// Path(s) to the git repositories to aggregate into one calendar
+    #[arg(default_value = ".")]
+    paths: Vec<String>,
+
+    /// Only include commits on or after this date (YYYY-MM-DD). Defaults to one year ago.
+    #[arg(long)]
+    since: Option<String>,
+
+    /// Only include commits on or before this date (YYYY-MM-DD). Defaults to today.
+    #[arg(long)]
+    until: Option<String>,
+
+    /// Only include commits whose author name or email contains this pattern
+    #[arg(long)]
+    author: Option<String>,
+
+    /// Color scheme for the calendar: green, red, or mono
+    #[arg(long, default_value = "green")]
+    color: String,
+
+    /// Glyph used to render each calendar cell
+    #[arg(long = "char", default_value = "█")]
+    glyph: String,
+
+    /// Show the commits on a single date (YYYY-MM-DD) instead of the calendar grid
+    #[arg(long)]
+    on: Option<String>,
+}
 
-    let repo = match Repository::discover(&path) {
-        Ok(r) => r,
-        Err(e) => {
-            eprintln!("{} {}", "Error:".red().bold(), e);
+/// A 5-entry RGB palette indexed by intensity bucket: [no commits, low, .., highest].
+type ColorScheme = [(u8, u8, u8); 5];
+
+const GREEN_SCHEME: ColorScheme = [(40, 40, 40), (14, 68, 41), (0, 109, 44), (38, 166, 65), (57, 211, 83)];
+const RED_SCHEME: ColorScheme = [(40, 40, 40), (69, 26, 26), (127, 29, 29), (185, 28, 28), (239, 68, 68)];
+const MONO_SCHEME: ColorScheme = [(40, 40, 40), (90, 90, 90), (130, 130, 130), (180, 180, 180), (230, 230, 230)];
+
+/// ASCII density glyphs used in place of the truecolor palette when color is unavailable,
+/// indexed the same way: [no commits, low, .., highest].
+const ASCII_DENSITY: [char; 5] = [' ', '.', ':', '*', '#'];
+
+fn parse_color_scheme(name: &str) -> ColorScheme {
+    match name {
+        "green" => GREEN_SCHEME,
+        "red" => RED_SCHEME,
+        "mono" => MONO_SCHEME,
+        _ => {
+            eprintln!(
+                "{} unknown color scheme {:?}, expected green, red, or mono",
+                "Error:".red().bold(),
+                name
+            );
             std::process::exit(1);
         }
+    }
+}
+
+/// Whether truecolor output should be used: disabled when stdout isn't a TTY (e.g. piped to a
+/// file or pager) or when the user has set `NO_COLOR`.
+fn should_use_color() -> bool {
+    env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+fn parse_glyph(value: &str) -> char {
+    value.chars().next().unwrap_or_else(|| {
+        eprintln!("{} --char value must not be empty", "Error:".red().bold());
+        std::process::exit(1);
+    })
+}
+
+/// Buckets nonzero daily commit counts into quartile-based intensity levels 1-4, so a single
+/// outlier day doesn't wash out the rest of the grid the way linear scaling would.
+struct QuantileScale {
+    /// Inclusive upper bound for buckets 1..=4, derived from the 25/50/75/100th percentiles.
+    thresholds: [usize; 4],
+}
+
+impl QuantileScale {
+    fn new(counts: &[usize]) -> Self {
+        let mut nonzero: Vec<usize> = counts.iter().copied().filter(|&c| c > 0).collect();
+        nonzero.sort_unstable();
+
+        if nonzero.is_empty() {
+            return QuantileScale { thresholds: [0, 0, 0, 0] };
+        }
+
+        let at_percentile = |p: f64| -> usize {
+            let idx = (((nonzero.len() - 1) as f64) * p).round() as usize;
+            nonzero[idx.min(nonzero.len() - 1)]
+        };
+
+        QuantileScale {
+            thresholds: [
+                at_percentile(0.25),
+                at_percentile(0.5),
+                at_percentile(0.75),
+                at_percentile(1.0),
+            ],
+        }
+    }
+
+    fn intensity(&self, count: usize) -> usize {
+        if count == 0 {
+            return 0;
+        }
+
+        self.thresholds
+            .iter()
+            .position(|&threshold| count <= threshold)
+            .map_or(4, |bucket| bucket + 1)
+    }
+}
+
+/// Inclusive date bounds applied to every revwalk in the program.
+#[derive(Clone, Copy)]
+struct DateWindow {
+    since: NaiveDate,
+    until: NaiveDate,
+}
+
+fn parse_date_arg(flag: &str, value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap_or_else(|_| {
+        eprintln!(
+            "{} invalid {} date {:?}, expected YYYY-MM-DD",
+            "Error:".red().bold(),
+            flag,
+            value
+        );
+        std::process::exit(1);
+    })
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let today = Local::now().date_naive();
+    let since = args
+        .since
+        .as_deref()
+        .map(|s| parse_date_arg("--since", s))
+        .unwrap_or_else(|| today - Duration::days(365));
+    let until = args
+        .until
+        .as_deref()
+        .map(|s| parse_date_arg("--until", s))
+        .unwrap_or(today);
+    let window = DateWindow { since, until };
+    let author = args.author.as_deref();
+    let scheme = parse_color_scheme(&args.color);
+    let use_color = should_use_color();
+    // Keep the header (and any other `colored` text) in sync with the grid's TTY/NO_COLOR
+    // decision, instead of letting `colored`'s own heuristics decide independently.
+    colored::control::set_override(use_color);
+    let glyph = parse_glyph(&args.glyph);
+    let on = args.on.as_deref().map(|s| parse_date_arg("--on", s));
+
+    let repos: Vec<Repository> = args
+        .paths
+        .iter()
+        .map(|path| match Repository::discover(path) {
+            Ok(r) => r,
+            Err(e) => {
+                eprintln!("{} {}", "Error:".red().bold(), e);
+                std::process::exit(1);
+            }
+        })
+        .collect();
+
+    let mailmaps: Vec<Mailmap> = repos.iter().map(Mailmap::load).collect();
+
+    let render_options = RenderOptions {
+        scheme: &scheme,
+        use_color,
+        glyph,
+        on,
     };
 
-    print_repo_info(&repo);
+    print_repo_info(&repos, &window, author, &mailmaps);
     println!();
-    print_contribution_calendar(&repo);
+    print_contribution_calendar(&repos, &mailmaps, &window, author, &render_options);
+}
+
+/// A commit author's resolved identity: canonical name and email.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Identity {
+    name: String,
+    email: String,
 }
 
-fn print_repo_info(repo: &Repository) {
+/// Maps `.mailmap`-aliased (name, email) pairs and bare emails to their canonical identity,
+/// so the same person under different spellings aggregates as one contributor.
+struct Mailmap {
+    by_name_email: HashMap<(String, String), Identity>,
+    by_email: HashMap<String, Identity>,
+}
+
+impl Mailmap {
+    fn load(repo: &Repository) -> Self {
+        let mut mailmap = Mailmap {
+            by_name_email: HashMap::new(),
+            by_email: HashMap::new(),
+        };
+
+        if let Some(workdir) = repo.workdir() {
+            if let Ok(contents) = std::fs::read_to_string(workdir.join(".mailmap")) {
+                mailmap.parse(&contents);
+            }
+        }
+
+        if let Ok(config) = repo.config() {
+            if let Ok(path) = config.get_string("mailmap.file") {
+                if let Ok(contents) = std::fs::read_to_string(&path) {
+                    mailmap.parse(&contents);
+                }
+            }
+        }
+
+        mailmap
+    }
+
+    fn parse(&mut self, contents: &str) {
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entries = parse_name_email_pairs(line);
+            let Some((canonical_name, canonical_email)) = entries.first().cloned() else {
+                continue;
+            };
+            let canonical = Identity {
+                name: canonical_name,
+                email: canonical_email,
+            };
+
+            // Register the canonical email itself, so a commit under the canonical address
+            // but a different name spelling still collapses to the canonical identity. This
+            // also makes a bare `Proper Name <email>` line (no trailing alias) take effect.
+            self.by_email
+                .entry(canonical.email.clone())
+                .or_insert_with(|| canonical.clone());
+
+            for (alias_name, alias_email) in entries.iter().skip(1) {
+                if alias_email.is_empty() {
+                    continue;
+                }
+                self.by_name_email
+                    .insert((alias_name.clone(), alias_email.clone()), canonical.clone());
+                self.by_email
+                    .entry(alias_email.clone())
+                    .or_insert_with(|| canonical.clone());
+            }
+        }
+    }
+
+    /// Resolves a raw commit (name, email) to its canonical identity, falling back to the
+    /// commit's own name/email when no mailmap entry matches.
+    fn resolve(&self, name: &str, email: &str) -> Identity {
+        if let Some(identity) = self.by_name_email.get(&(name.to_string(), email.to_string())) {
+            return self.with_commit_name_fallback(identity, name);
+        }
+        if let Some(identity) = self.by_email.get(email) {
+            return self.with_commit_name_fallback(identity, name);
+        }
+        Identity {
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    /// A canonical pair like `<new@email> <old@email>` carries no proper name, only an email
+    /// rewrite. In that case keep the commit's own name instead of rendering a blank author.
+    fn with_commit_name_fallback(&self, identity: &Identity, commit_name: &str) -> Identity {
+        if identity.name.is_empty() {
+            Identity {
+                name: commit_name.to_string(),
+                email: identity.email.clone(),
+            }
+        } else {
+            identity.clone()
+        }
+    }
+}
+
+/// Parses a mailmap line into its `(name, email)` pairs, in order: canonical identity first,
+/// then zero or more commit aliases. A pair's name may be empty (a bare `<email>` alias).
+fn parse_name_email_pairs(line: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut rest = line;
+
+    while let Some(lt) = rest.find('<') {
+        let name = rest[..lt].trim().to_string();
+        let after = &rest[lt + 1..];
+        let Some(gt) = after.find('>') else { break };
+        let email = after[..gt].trim().to_string();
+        pairs.push((name, email));
+        rest = &after[gt + 1..];
+    }
+
+    pairs
+}
+
+fn print_repo_info(repos: &[Repository], window: &DateWindow, author: Option<&str>, mailmaps: &[Mailmap]) {
+    if repos.len() == 1 {
+        print_single_repo_info(&repos[0], window, author, &mailmaps[0]);
+        return;
+    }
+
+    println!("{}", format!("  {} repositories", repos.len()).cyan().bold());
+    println!("{}", "─".repeat(40).dimmed());
+
+    let mut total_commits = 0;
+    let mut total_size = 0u64;
+    let mut combined_contributors: HashMap<Identity, usize> = HashMap::new();
+    let mut combined_languages: HashMap<String, (usize, usize)> = HashMap::new();
+
+    for (repo, mailmap) in repos.iter().zip(mailmaps) {
+        let workdir = repo.workdir().unwrap_or(Path::new("."));
+        let repo_name = workdir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown");
+
+        let commit_count = count_commits(repo, window, author);
+        total_commits += commit_count;
+        println!("  {}  {} ({})", "Repo:".white().bold(), repo_name.yellow(), commit_count.to_string().green());
+
+        for (identity, count) in get_contributor_counts(repo, window, author, mailmap) {
+            *combined_contributors.entry(identity).or_insert(0) += count;
+        }
+
+        let code_stats = detect_languages_and_loc(workdir);
+        for (lang, files, lines) in code_stats.languages {
+            let entry = combined_languages.entry(lang).or_insert((0, 0));
+            entry.0 += files;
+            entry.1 += lines;
+        }
+
+        total_size += get_repo_size(workdir);
+    }
+
+    println!("  {}  {}", "Total commits:".white().bold(), total_commits.to_string().green());
+    println!("  {}  {}", "Size:".white().bold(), format_size(total_size));
+
+    if !combined_contributors.is_empty() {
+        let mut sorted: Vec<_> = combined_contributors.into_iter().collect();
+        sorted.sort_by(|a, b| b.1.cmp(&a.1));
+        let top_contributors: Vec<_> = sorted.iter().take(3).collect();
+        println!("  {}  {}", "Authors:".white().bold(),
+            top_contributors.iter()
+                .map(|(identity, count)| format!("{} ({})", identity.name, count))
+                .collect::<Vec<_>>()
+                .join(", "));
+    }
+
+    if !combined_languages.is_empty() {
+        let mut sorted: Vec<_> = combined_languages
+            .into_iter()
+            .map(|(name, (files, lines))| (name, files, lines))
+            .collect();
+        sorted.sort_by(|a, b| b.2.cmp(&a.2));
+
+        print!("  {}  ", "LOC:".white().bold());
+        let langs: Vec<_> = sorted.iter().take(5).collect();
+        for (i, (lang, _, lines)) in langs.iter().enumerate() {
+            let colored_lang = colorize_lang(lang);
+            print!("{} ({})", colored_lang, format_number(*lines));
+            if i < langs.len() - 1 {
+                print!(", ");
+            }
+        }
+        println!();
+    }
+}
+
+fn print_single_repo_info(repo: &Repository, window: &DateWindow, author: Option<&str>, mailmap: &Mailmap) {
     let workdir = repo.workdir().unwrap_or(Path::new("."));
     let repo_name = workdir
         .file_name()
@@ -36,10 +401,10 @@ fn print_repo_info(repo: &Repository) {
         .unwrap_or_else(|| "detached".to_string());
 
     // Count commits
-    let commit_count = count_commits(repo);
+    let commit_count = count_commits(repo, window, author);
 
     // Get contributors
-    let contributors = get_contributors(repo);
+    let contributors = get_contributors(repo, window, author, mailmap);
 
     // Get languages and LOC
     let code_stats = detect_languages_and_loc(workdir);
@@ -111,34 +476,93 @@ fn colorize_lang(lang: &str) -> ColoredString {
     }
 }
 
-fn count_commits(repo: &Repository) -> usize {
+/// Extracts the calendar date of a commit in the local machine's timezone (not the commit's
+/// own timezone offset), if its timestamp is representable.
+fn commit_date(commit: &Commit) -> Option<NaiveDate> {
+    let time = commit.time();
+    chrono::DateTime::from_timestamp(time.seconds(), 0).map(|dt| dt.with_timezone(&Local).date_naive())
+}
+
+fn commit_in_window(date: NaiveDate, window: &DateWindow) -> bool {
+    date >= window.since && date <= window.until
+}
+
+fn commit_matches_author(commit: &Commit, pattern: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let author = commit.author();
+    let name = author.name().unwrap_or("").to_lowercase();
+    let email = author.email().unwrap_or("").to_lowercase();
+    name.contains(&pattern) || email.contains(&pattern)
+}
+
+fn count_commits(repo: &Repository, window: &DateWindow, author: Option<&str>) -> usize {
     let mut revwalk = match repo.revwalk() {
         Ok(r) => r,
         Err(_) => return 0,
     };
     revwalk.push_head().ok();
-    revwalk.count()
+
+    revwalk
+        .filter_map(Result::ok)
+        .filter_map(|oid| repo.find_commit(oid).ok())
+        .filter(|commit| matches!(commit_date(commit), Some(d) if commit_in_window(d, window)))
+        .filter(|commit| match author {
+            Some(pattern) => commit_matches_author(commit, pattern),
+            None => true,
+        })
+        .count()
+}
+
+fn get_contributors(
+    repo: &Repository,
+    window: &DateWindow,
+    author: Option<&str>,
+    mailmap: &Mailmap,
+) -> Vec<(String, usize)> {
+    let mut sorted: Vec<_> = get_contributor_counts(repo, window, author, mailmap)
+        .into_iter()
+        .map(|(identity, count)| (identity.name, count))
+        .collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted
 }
 
-fn get_contributors(repo: &Repository) -> Vec<(String, usize)> {
-    let mut contributors: HashMap<String, usize> = HashMap::new();
+fn get_contributor_counts(
+    repo: &Repository,
+    window: &DateWindow,
+    author: Option<&str>,
+    mailmap: &Mailmap,
+) -> HashMap<Identity, usize> {
+    let mut contributors: HashMap<Identity, usize> = HashMap::new();
 
     let mut revwalk = match repo.revwalk() {
         Ok(r) => r,
-        Err(_) => return vec![],
+        Err(_) => return contributors,
     };
     revwalk.push_head().ok();
 
-    for oid in revwalk.filter_map(Result::ok).take(1000) {
+    // No cap here: count_commits walks full history too, so --since/--until stay the only
+    // bound on what gets counted and the two stay consistent with each other.
+    for oid in revwalk.filter_map(Result::ok) {
         if let Ok(commit) = repo.find_commit(oid) {
-            let name = commit.author().name().unwrap_or("Unknown").to_string();
-            *contributors.entry(name).or_insert(0) += 1;
+            let in_window = matches!(commit_date(&commit), Some(d) if commit_in_window(d, window));
+            if !in_window {
+                continue;
+            }
+            if let Some(pattern) = author {
+                if !commit_matches_author(&commit, pattern) {
+                    continue;
+                }
+            }
+            let sig = commit.author();
+            let name = sig.name().unwrap_or("Unknown").to_string();
+            let email = sig.email().unwrap_or("").to_string();
+            let identity = mailmap.resolve(&name, &email);
+            *contributors.entry(identity).or_insert(0) += 1;
         }
     }
 
-    let mut sorted: Vec<_> = contributors.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
-    sorted
+    contributors
 }
 
 struct CodeStats {
@@ -266,47 +690,94 @@ fn format_number(n: usize) -> String {
     }
 }
 
-fn print_contribution_calendar(repo: &Repository) {
-    let today = Local::now().date_naive();
-    let weeks = 52;
-    let start_date = today - Duration::days((weeks * 7) as i64);
-
-    // Adjust to start from Sunday
-    let days_since_sunday = start_date.weekday().num_days_from_sunday() as i64;
-    let start_date = start_date - Duration::days(days_since_sunday);
+/// A lightweight record of a single commit, enough to render an `--on` day's detail listing.
+struct CommitRecord {
+    short_hash: String,
+    author: String,
+    summary: String,
+}
 
-    // Collect commits by date
-    let mut commits_by_date: HashMap<NaiveDate, usize> = HashMap::new();
+/// Rendering options for the calendar grid, kept separate from the data-selection arguments
+/// (`window`, `author`) so the signature doesn't grow with every new cosmetic flag.
+struct RenderOptions<'a> {
+    scheme: &'a ColorScheme,
+    use_color: bool,
+    glyph: char,
+    on: Option<NaiveDate>,
+}
 
-    let mut revwalk = match repo.revwalk() {
-        Ok(r) => r,
-        Err(_) => return,
+fn print_contribution_calendar(
+    repos: &[Repository],
+    mailmaps: &[Mailmap],
+    window: &DateWindow,
+    author: Option<&str>,
+    options: &RenderOptions,
+) {
+    // Align the grid to start on a Sunday on or before `window.since`.
+    let days_since_sunday = window.since.weekday().num_days_from_sunday() as i64;
+    let start_date = window.since - Duration::days(days_since_sunday);
+    let weeks = (window.until - start_date).num_days() / 7 + 1;
+
+    // `--on` drills into a single date and must not be constrained by the heatmap's own
+    // `[since, until]` window (which defaults to the last year) — otherwise asking about an
+    // older date than that default silently reports no commits.
+    let collection_window = match options.on {
+        Some(date) => DateWindow { since: date, until: date },
+        None => *window,
     };
-    revwalk.push_head().ok();
-    revwalk.set_sorting(Sort::TIME).ok();
 
-    for oid in revwalk.filter_map(Result::ok) {
-        if let Ok(commit) = repo.find_commit(oid) {
-            let time = commit.time();
-            let date = chrono::DateTime::from_timestamp(time.seconds(), 0)
-                .map(|dt| dt.with_timezone(&Local).date_naive());
+    // Collect commits by date, summed across all repos
+    let mut commits_by_date: HashMap<NaiveDate, Vec<CommitRecord>> = HashMap::new();
+
+    for (repo, mailmap) in repos.iter().zip(mailmaps) {
+        let mut revwalk = match repo.revwalk() {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        revwalk.push_head().ok();
+        revwalk.set_sorting(Sort::TIME).ok();
+
+        for oid in revwalk.filter_map(Result::ok) {
+            if let Ok(commit) = repo.find_commit(oid) {
+                if let Some(pattern) = author {
+                    if !commit_matches_author(&commit, pattern) {
+                        continue;
+                    }
+                }
 
-            if let Some(date) = date {
-                if date >= start_date && date <= today {
-                    *commits_by_date.entry(date).or_insert(0) += 1;
+                if let Some(date) = commit_date(&commit) {
+                    if commit_in_window(date, &collection_window) {
+                        let sig = commit.author();
+                        let name = sig.name().unwrap_or("Unknown").to_string();
+                        let email = sig.email().unwrap_or("").to_string();
+                        let identity = mailmap.resolve(&name, &email);
+
+                        commits_by_date.entry(date).or_default().push(CommitRecord {
+                            short_hash: oid.to_string()[..7].to_string(),
+                            author: identity.name,
+                            summary: commit.summary().unwrap_or("").to_string(),
+                        });
+                    }
                 }
             }
         }
     }
 
-    // Find max for intensity scaling
-    let max_commits = commits_by_date.values().max().copied().unwrap_or(1).max(1);
+    if let Some(date) = options.on {
+        print_day_detail(&commits_by_date, date);
+        return;
+    }
+
+    // Quartile-based intensity scaling over the nonzero days, so one outlier day doesn't
+    // collapse the rest of the grid to a single color.
+    let counts: Vec<usize> = commits_by_date.values().map(Vec::len).collect();
+    let scale = QuantileScale::new(&counts);
 
     // Print month labels
     print!("     ");
     let mut current_month = None;
     for week in 0..weeks {
-        let week_start = start_date + Duration::days((week * 7) as i64);
+        let week_start = start_date + Duration::days(week * 7);
         let month = week_start.month();
 
         if current_month != Some(month) {
@@ -329,15 +800,15 @@ fn print_contribution_calendar(repo: &Repository) {
         }
 
         for week in 0..weeks {
-            let date = start_date + Duration::days((week * 7 + day_idx) as i64);
+            let date = start_date + Duration::days(week * 7 + day_idx as i64);
 
-            if date > today {
+            if date > window.until || date < window.since {
                 print!("  ");
                 continue;
             }
 
-            let count = commits_by_date.get(&date).copied().unwrap_or(0);
-            let block = get_contribution_block(count, max_commits);
+            let count = commits_by_date.get(&date).map_or(0, Vec::len);
+            let block = get_contribution_block(count, &scale, options.scheme, options.use_color, options.glyph);
             print!("{} ", block);
         }
         println!();
@@ -346,35 +817,65 @@ fn print_contribution_calendar(repo: &Repository) {
     // Print legend
     println!();
     print!("     Less ");
-    print!("{} ", "█".truecolor(40, 40, 40));
-    print!("{} ", "█".truecolor(250, 204, 21));
-    print!("{} ", "█".truecolor(251, 146, 60));
-    print!("{} ", "█".truecolor(134, 239, 172));
-    print!("{} ", "█".truecolor(34, 197, 94));
+    for level in 0..5 {
+        print!("{} ", legend_glyph(level, options.scheme, options.use_color, options.glyph));
+    }
     println!("More");
 
     // Print stats
-    let total_commits: usize = commits_by_date.values().sum();
+    let total_commits: usize = commits_by_date.values().map(Vec::len).sum();
     let active_days = commits_by_date.len();
     println!();
-    println!("     {} commits in the last year across {} days",
+    println!("     {} commits from {} to {} across {} days",
         total_commits.to_string().green().bold(),
+        window.since,
+        window.until,
         active_days.to_string().cyan());
 }
 
-fn get_contribution_block(count: usize, max: usize) -> ColoredString {
-    if count == 0 {
-        return "█".truecolor(40, 40, 40);
+/// Prints the commits on a single date: short hash, mailmap-resolved author, and title.
+fn print_day_detail(commits_by_date: &HashMap<NaiveDate, Vec<CommitRecord>>, date: NaiveDate) {
+    println!("  {}  {}", "Commits on:".white().bold(), date.to_string().yellow());
+    println!("{}", "─".repeat(40).dimmed());
+
+    match commits_by_date.get(&date) {
+        Some(commits) if !commits.is_empty() => {
+            for commit in commits {
+                println!(
+                    "  {}  {}  {}",
+                    commit.short_hash.yellow(),
+                    commit.author.cyan(),
+                    commit.summary
+                );
+            }
+        }
+        _ => println!("  No commits on {}", date),
     }
+}
 
-    let intensity = (count as f64 / max as f64 * 4.0).ceil() as usize;
+fn get_contribution_block(
+    count: usize,
+    scale: &QuantileScale,
+    scheme: &ColorScheme,
+    use_color: bool,
+    glyph: char,
+) -> String {
+    let intensity = scale.intensity(count);
+
+    if use_color {
+        let (r, g, b) = scheme[intensity];
+        glyph.to_string().truecolor(r, g, b).to_string()
+    } else {
+        ASCII_DENSITY[intensity].to_string()
+    }
+}
 
-    // Gradient: yellow → orange → light green → green
-    match intensity {
-        1 => "█".truecolor(250, 204, 21),   // yellow
-        2 => "█".truecolor(251, 146, 60),   // orange
-        3 => "█".truecolor(134, 239, 172),  // light green
-        _ => "█".truecolor(34, 197, 94),    // green
+fn legend_glyph(level: usize, scheme: &ColorScheme, use_color: bool, glyph: char) -> String {
+    if use_color {
+        let (r, g, b) = scheme[level];
+        glyph.to_string().truecolor(r, g, b).to_string()
+    } else {
+        ASCII_DENSITY[level].to_string()
     }
 }
 